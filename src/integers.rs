@@ -0,0 +1,106 @@
+use crate::{Bitstring, BitstringError};
+use bit_vec::BitVec;
+
+impl Bitstring<'_> {
+    /// Converts this [`Bitstring`] to a `u64`, treating it as an MSB-first unsigned integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this [`Bitstring`] has more than 64 bits. Use [`Bitstring::try_to_u64`] for a
+    /// non-panicking version.
+    #[must_use]
+    pub fn to_u64(&self) -> u64 {
+        self.try_to_u64()
+            .expect("bitstring has more than 64 bits, consider using try_to_u64 instead")
+    }
+
+    /// Converts this [`Bitstring`] to a `u64`, treating it as an MSB-first unsigned integer.
+    /// Errors with [`BitstringError::TooManyBits`] if this [`Bitstring`] has more than 64 bits.
+    pub fn try_to_u64(&self) -> Result<u64, BitstringError> {
+        if self.len() > 64 {
+            return Err(BitstringError::TooManyBits {
+                len: self.len(),
+                max_bits: 64,
+            });
+        }
+        Ok(self.iter().fold(0u64, |acc, bit| (acc << 1) | u64::from(bit)))
+    }
+}
+
+macro_rules! impl_integer_conversions {
+    ($ty:ty, $bits:expr) => {
+        impl From<$ty> for Bitstring<'static> {
+            fn from(value: $ty) -> Self {
+                Bitstring::from(BitVec::from_bytes(&value.to_be_bytes()))
+            }
+        }
+
+        impl TryFrom<Bitstring<'_>> for $ty {
+            type Error = BitstringError;
+
+            fn try_from(value: Bitstring<'_>) -> Result<Self, Self::Error> {
+                if value.len() != $bits {
+                    return Err(BitstringError::UnexpectedLength {
+                        expected: $bits,
+                        actual: value.len(),
+                    });
+                }
+                Ok(value.iter().fold(0 as $ty, |acc, bit| (acc << 1) | (bit as $ty)))
+            }
+        }
+    };
+}
+
+impl_integer_conversions!(u8, 8);
+impl_integer_conversions!(u16, 16);
+impl_integer_conversions!(u32, 32);
+impl_integer_conversions!(u64, 64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bitstring, BitstringError};
+
+    #[test]
+    fn test_from_u8() {
+        let bs = Bitstring::from(0b1011_0000u8);
+        assert_eq!(format!("{bs}"), "10110000");
+    }
+
+    #[test]
+    fn test_try_from_u8() {
+        let bs = Bitstring::from(0b1011_0000u8);
+        let n: u8 = bs.try_into().unwrap();
+        assert_eq!(n, 0b1011_0000);
+    }
+
+    #[test]
+    fn test_try_from_wrong_length() {
+        let bs = Bitstring::from(0b1011_0000u16);
+        let result: Result<u8, _> = bs.try_into();
+        assert!(matches!(
+            result,
+            Err(BitstringError::UnexpectedLength {
+                expected: 8,
+                actual: 16
+            })
+        ));
+    }
+
+    #[test]
+    fn test_to_u64() {
+        let bs = Bitstring::from(42u32);
+        assert_eq!(bs.to_u64(), 42);
+    }
+
+    #[test]
+    fn test_try_to_u64_too_many_bits() {
+        let bs = Bitstring::from(vec![true; 65].into_iter().collect::<bit_vec::BitVec>());
+        assert!(matches!(
+            bs.try_to_u64(),
+            Err(BitstringError::TooManyBits {
+                len: 65,
+                max_bits: 64
+            })
+        ));
+    }
+}
@@ -2,16 +2,25 @@ use bit_vec::BitVec;
 use duckdb::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, Value, ValueRef};
 use std::borrow::Cow;
 use std::fmt;
+use std::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+use std::sync::OnceLock;
 
 #[derive(Debug, Clone)]
 /// Type representing a bitstring that can be converted to a DuckDB BIT type (or the other way around).
-/// Under the hood this is just a wrapper for [`bit_vec::BitVec`] with the necessary traits ([`FromSql`]/[`ToSql`]) implemented.
+/// Under the hood this wraps a byte-aligned [`bit_vec::BitVec`] together with a `start_offset`
+/// counting how many leading bits are padding, so reading a value out of DuckDB (which always
+/// hands back byte-aligned data) needs no bit-shifting. [`Bitstring::as_bitvec`] materializes the
+/// shifted, logical [`BitVec`] lazily and caches it for callers who need a contiguous view.
 /// Use [`Bitstring::from`] to obtain a [`Bitstring`] from an owned or borrowed [`bit_vec::BitVec`].
-pub struct Bitstring<'a>(Cow<'a, BitVec>);
+pub struct Bitstring<'a> {
+    raw: Cow<'a, BitVec>,
+    start_offset: usize,
+    shifted: OnceLock<BitVec>,
+}
 
 impl<'a> ToSql for Bitstring<'a> {
     fn to_sql(&self) -> duckdb::Result<ToSqlOutput<'_>> {
-        if self.as_bitvec().is_empty() {
+        if self.is_empty() {
             Err(duckdb::Error::ToSqlConversionFailure(Box::new(
                 BitstringError::EmptyBitstring,
             )))
@@ -23,7 +32,10 @@ impl<'a> ToSql for Bitstring<'a> {
 
 impl fmt::Display for Bitstring<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.as_bitvec())
+        for bit in self.iter() {
+            write!(f, "{}", u8::from(bit))?;
+        }
+        Ok(())
     }
 }
 
@@ -37,6 +49,15 @@ pub enum BitstringError {
     /// Occurs when DuckDB returns an invalid representation of a BIT type.
     /// This should not happen in practice so please let me know if you run into this error.
     RawDataTooShort(usize),
+    /// Occurs when performing a bitwise AND/OR/XOR between two [`Bitstring`]s of different
+    /// lengths, mirroring DuckDB's requirement that BIT operands have equal length.
+    LengthMismatch { left: usize, right: usize },
+    /// Occurs when converting a [`Bitstring`] into a fixed-width integer type whose width
+    /// doesn't match the [`Bitstring`]'s length.
+    UnexpectedLength { expected: usize, actual: usize },
+    /// Occurs when converting a [`Bitstring`] into an integer type that is too narrow to hold
+    /// all of its bits, e.g. via [`Bitstring::try_to_u64`].
+    TooManyBits { len: usize, max_bits: usize },
 }
 
 impl fmt::Display for BitstringError {
@@ -44,7 +65,10 @@ impl fmt::Display for BitstringError {
         match self {
             BitstringError::RawDataBadPadding(pad) => write!(f, "raw data padding byte should be 0-7, was {pad}"),
             BitstringError::RawDataTooShort(len) => write!(f, "raw data too short (should be at least 2 bytes, was {len} bytes long)"),
-            BitstringError::EmptyBitstring => write!(f, "DuckDB does not support empty bit strings, consider using a nullable column and Option<Bitstring>")
+            BitstringError::EmptyBitstring => write!(f, "DuckDB does not support empty bit strings, consider using a nullable column and Option<Bitstring>"),
+            BitstringError::LengthMismatch { left, right } => write!(f, "bitstrings must have equal length for this operation, but left was {left} bits and right was {right} bits"),
+            BitstringError::UnexpectedLength { expected, actual } => write!(f, "expected a bitstring of {expected} bits, got one of {actual} bits"),
+            BitstringError::TooManyBits { len, max_bits } => write!(f, "bitstring has {len} bits, which does not fit in {max_bits} bits")
         }
     }
 }
@@ -53,13 +77,132 @@ impl std::error::Error for BitstringError {}
 
 impl<'a> Bitstring<'a> {
     #[must_use]
-    pub fn into_bitvec(self) -> BitVec {
-        self.0.into_owned()
+    pub fn into_bitvec(mut self) -> BitVec {
+        match self.shifted.take() {
+            Some(shifted) => shifted,
+            None if self.start_offset == 0 => self.raw.into_owned(),
+            None => self.raw.into_owned().split_off(self.start_offset),
+        }
+    }
+
+    /// Materializes the logical, byte-unaligned bits of this [`Bitstring`] as a contiguous
+    /// [`BitVec`], shifting past `start_offset` on first access and caching the result so
+    /// repeated calls are free. When `start_offset` is zero (true for any [`Bitstring`] not read
+    /// straight off the wire) `self.raw` is already the logical view, so this borrows it directly
+    /// with no allocation.
+    #[must_use]
+    pub fn as_bitvec(&self) -> &BitVec {
+        if self.start_offset == 0 {
+            return self.raw.as_ref();
+        }
+        self.shifted.get_or_init(|| self.raw.clone().into_owned().split_off(self.start_offset))
+    }
+
+    /// The number of bits in this [`Bitstring`], excluding the leading padding bits.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.raw.len() - self.start_offset
+    }
+
+    /// Returns `true` if this [`Bitstring`] has no bits.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
+    /// Returns the bit at position `i`, or `None` if `i` is out of bounds.
     #[must_use]
-    pub fn as_bitvec(&'a self) -> &'a BitVec {
-        self.0.as_ref()
+    pub fn get(&self, i: usize) -> Option<bool> {
+        i.checked_add(self.start_offset)
+            .and_then(|j| self.raw.get(j))
+    }
+
+    /// Iterates over the bits of this [`Bitstring`], skipping the leading padding bits.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        self.raw.iter().skip(self.start_offset)
+    }
+
+    /// The number of set bits in this [`Bitstring`], matching DuckDB's `bit_count`.
+    #[must_use]
+    pub fn popcount(&self) -> usize {
+        self.iter().filter(|&b| b).count()
+    }
+
+    /// The number of bits in this [`Bitstring`], matching DuckDB's `bit_length`.
+    /// Alias for [`Bitstring::len`].
+    #[must_use]
+    pub fn bit_length(&self) -> usize {
+        self.len()
+    }
+
+    /// Returns the bit at position `i`, or `None` if `i` is out of bounds.
+    /// Alias for [`Bitstring::get`].
+    #[must_use]
+    pub fn get_bit(&self, i: usize) -> Option<bool> {
+        self.get(i)
+    }
+
+    /// Sets the bit at position `i` to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn set_bit(&mut self, i: usize, value: bool) {
+        let j = i
+            .checked_add(self.start_offset)
+            .expect("bit index out of bounds");
+        self.raw.to_mut().set(j, value);
+        self.shifted = OnceLock::new();
+    }
+
+    fn from_bits(bits: impl IntoIterator<Item = bool>) -> Bitstring<'static> {
+        Bitstring::from(bits.into_iter().collect::<BitVec>())
+    }
+
+    /// Combines `self` and `other` bit-by-bit with `f`, erroring with
+    /// [`BitstringError::LengthMismatch`] if the two [`Bitstring`]s don't have equal length.
+    fn combine(
+        &self,
+        other: &Bitstring<'_>,
+        f: impl Fn(bool, bool) -> bool,
+    ) -> Result<Bitstring<'static>, BitstringError> {
+        if self.len() != other.len() {
+            return Err(BitstringError::LengthMismatch {
+                left: self.len(),
+                right: other.len(),
+            });
+        }
+        Ok(Bitstring::from_bits(
+            self.iter().zip(other.iter()).map(|(a, b)| f(a, b)),
+        ))
+    }
+
+    /// Converts this [`Bitstring`] to a [`ToSqlOutput`] carrying DuckDB's binary wire
+    /// representation for the BIT type instead of the text round-trip used by [`ToSql::to_sql`].
+    /// This is a more compact encoding for large bitstrings, but there is no implicit BLOB→BIT
+    /// cast in the binder, so the bound parameter must still be cast explicitly with `::bit`.
+    pub fn to_sql_blob(&self) -> duckdb::Result<ToSqlOutput<'_>> {
+        if self.is_empty() {
+            Err(duckdb::Error::ToSqlConversionFailure(Box::new(
+                BitstringError::EmptyBitstring,
+            )))
+        } else {
+            Ok(ToSqlOutput::Owned(Value::Blob(self.to_raw())))
+        }
+    }
+
+    /// Encodes this [`Bitstring`] into DuckDB's wire representation for the BIT type: a leading
+    /// padding byte (0-7) followed by the bits packed MSB-first, with the leading `pad` filler
+    /// bits of the first data byte set to 1. This is the inverse of [`Bitstring::from_raw`].
+    fn to_raw(&self) -> Vec<u8> {
+        let len = self.len();
+        let pad = (8 - (len % 8)) % 8;
+        let mut padded = BitVec::from_elem(pad, true);
+        padded.extend(self.iter());
+        let mut bytes = Vec::with_capacity(1 + padded.len() / 8);
+        bytes.push(pad as u8);
+        bytes.extend(padded.to_bytes());
+        bytes
     }
 
     fn from_raw<'b>(bytes: &[u8]) -> Result<Bitstring<'b>, BitstringError> {
@@ -68,25 +211,125 @@ impl<'a> Bitstring<'a> {
         } else if bytes[0] > 7 {
             Err(BitstringError::RawDataBadPadding(bytes[0]))
         } else {
-            let mut raw_vec = BitVec::from_bytes(&bytes[1..]);
-            if bytes[0] == 0 {
-                Ok(Bitstring::from(raw_vec))
-            } else {
-                Ok(Bitstring::from(raw_vec.split_off(bytes[0].into())))
-            }
+            let raw_vec = BitVec::from_bytes(&bytes[1..]);
+            Ok(Bitstring {
+                raw: Cow::Owned(raw_vec),
+                start_offset: bytes[0].into(),
+                shifted: OnceLock::new(),
+            })
         }
     }
 }
 
 impl From<BitVec> for Bitstring<'_> {
     fn from(v: BitVec) -> Bitstring<'static> {
-        Bitstring(Cow::Owned(v))
+        Bitstring {
+            raw: Cow::Owned(v),
+            start_offset: 0,
+            shifted: OnceLock::new(),
+        }
     }
 }
 
 impl<'a> From<&'a BitVec> for Bitstring<'a> {
     fn from(v: &'a BitVec) -> Bitstring<'a> {
-        Bitstring(Cow::Borrowed(v))
+        Bitstring {
+            raw: Cow::Borrowed(v),
+            start_offset: 0,
+            shifted: OnceLock::new(),
+        }
+    }
+}
+
+impl<'a, 'b> BitAnd<&Bitstring<'b>> for &Bitstring<'a> {
+    type Output = Result<Bitstring<'static>, BitstringError>;
+    fn bitand(self, rhs: &Bitstring<'b>) -> Self::Output {
+        self.combine(rhs, |a, b| a && b)
+    }
+}
+
+impl<'a, 'b> BitAnd<Bitstring<'b>> for Bitstring<'a> {
+    type Output = Result<Bitstring<'static>, BitstringError>;
+    fn bitand(self, rhs: Bitstring<'b>) -> Self::Output {
+        (&self).bitand(&rhs)
+    }
+}
+
+impl<'a, 'b> BitOr<&Bitstring<'b>> for &Bitstring<'a> {
+    type Output = Result<Bitstring<'static>, BitstringError>;
+    fn bitor(self, rhs: &Bitstring<'b>) -> Self::Output {
+        self.combine(rhs, |a, b| a || b)
+    }
+}
+
+impl<'a, 'b> BitOr<Bitstring<'b>> for Bitstring<'a> {
+    type Output = Result<Bitstring<'static>, BitstringError>;
+    fn bitor(self, rhs: Bitstring<'b>) -> Self::Output {
+        (&self).bitor(&rhs)
+    }
+}
+
+impl<'a, 'b> BitXor<&Bitstring<'b>> for &Bitstring<'a> {
+    type Output = Result<Bitstring<'static>, BitstringError>;
+    fn bitxor(self, rhs: &Bitstring<'b>) -> Self::Output {
+        self.combine(rhs, |a, b| a != b)
+    }
+}
+
+impl<'a, 'b> BitXor<Bitstring<'b>> for Bitstring<'a> {
+    type Output = Result<Bitstring<'static>, BitstringError>;
+    fn bitxor(self, rhs: Bitstring<'b>) -> Self::Output {
+        (&self).bitxor(&rhs)
+    }
+}
+
+impl Not for &Bitstring<'_> {
+    type Output = Bitstring<'static>;
+    fn not(self) -> Bitstring<'static> {
+        Bitstring::from_bits(self.iter().map(|b| !b))
+    }
+}
+
+impl Not for Bitstring<'_> {
+    type Output = Bitstring<'static>;
+    fn not(self) -> Bitstring<'static> {
+        !&self
+    }
+}
+
+impl Shl<usize> for &Bitstring<'_> {
+    type Output = Bitstring<'static>;
+    fn shl(self, n: usize) -> Bitstring<'static> {
+        let len = self.len();
+        Bitstring::from_bits((0..len).map(|i| match i.checked_add(n) {
+            Some(j) if j < len => self.get(j).unwrap(),
+            _ => false,
+        }))
+    }
+}
+
+impl Shl<usize> for Bitstring<'_> {
+    type Output = Bitstring<'static>;
+    fn shl(self, n: usize) -> Bitstring<'static> {
+        &self << n
+    }
+}
+
+impl Shr<usize> for &Bitstring<'_> {
+    type Output = Bitstring<'static>;
+    fn shr(self, n: usize) -> Bitstring<'static> {
+        let len = self.len();
+        Bitstring::from_bits((0..len).map(|i| match i.checked_sub(n) {
+            Some(j) => self.get(j).unwrap(),
+            None => false,
+        }))
+    }
+}
+
+impl Shr<usize> for Bitstring<'_> {
+    type Output = Bitstring<'static>;
+    fn shr(self, n: usize) -> Bitstring<'static> {
+        &self >> n
     }
 }
 
@@ -105,6 +348,15 @@ impl From<BitstringError> for FromSqlError {
     }
 }
 
+#[cfg(feature = "integer-conversions")]
+mod integers;
+
+#[cfg(feature = "vec-bool")]
+mod vec_bool;
+
+#[cfg(feature = "serde")]
+mod serde;
+
 #[doc = include_str!("../README.md")]
 #[cfg(doctest)]
 pub struct _ReadmeDoctests;
@@ -211,6 +463,58 @@ mod tests {
         assert_eq!(s, "1");
     }
 
+    #[test]
+    fn test_accessors_skip_start_offset() {
+        let bytes = vec![3, 0b11100101, 0b11100101, 0b00000101];
+        let bs = Bitstring::from_raw(&bytes).unwrap();
+
+        assert_eq!(bs.len(), 21);
+        assert!(!bs.is_empty());
+        assert_eq!(bs.get(0), Some(false));
+        assert_eq!(bs.get(1), Some(false));
+        assert_eq!(bs.get(20), Some(true));
+        assert_eq!(bs.get(21), None);
+        assert_eq!(
+            bs.iter().map(|b| if b { '1' } else { '0' }).collect::<String>(),
+            "001011110010100000101"
+        );
+    }
+
+    #[test]
+    fn test_get_out_of_bounds_does_not_overflow() {
+        let bytes = vec![3, 0b11100101, 0b11100101, 0b00000101];
+        let bs = Bitstring::from_raw(&bytes).unwrap();
+        assert_eq!(bs.get(usize::MAX), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit index out of bounds")]
+    fn test_set_bit_out_of_bounds_does_not_overflow() {
+        let bytes = vec![3, 0b11100101, 0b11100101, 0b00000101];
+        let mut bs = Bitstring::from_raw(&bytes).unwrap();
+        bs.set_bit(usize::MAX, true);
+    }
+
+    #[test]
+    fn test_as_bitvec_zero_offset_borrows_raw() {
+        let bv = BitVec::from_bytes(&[0b11100101, 0b11100101, 0b00000101]);
+        let bs = Bitstring::from(bv);
+
+        assert_eq!(bs.as_bitvec() as *const BitVec, bs.raw.as_ref() as *const BitVec);
+        assert_eq!(format!("{}", bs.as_bitvec()), "111001011110010100000101");
+    }
+
+    #[test]
+    fn test_as_bitvec_is_cached() {
+        let bytes = vec![3, 0b11100101, 0b11100101, 0b00000101];
+        let bs = Bitstring::from_raw(&bytes).unwrap();
+
+        let first = bs.as_bitvec() as *const BitVec;
+        let second = bs.as_bitvec() as *const BitVec;
+        assert_eq!(first, second);
+        assert_eq!(format!("{}", bs.as_bitvec()), "001011110010100000101");
+    }
+
     #[test]
     fn test_tosql() {
         let bv = Bitstring::from(BitVec::from_bytes(&[0b11100101, 0b11100101, 0b00000101]));
@@ -221,10 +525,155 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tosql_blob() {
+        let bv = Bitstring::from(BitVec::from_bytes(&[0b11100101, 0b11100101, 0b00000101]));
+        let s = bv.to_sql_blob().unwrap();
+        assert_eq!(
+            s,
+            ToSqlOutput::Owned(Value::Blob(vec![0, 0b11100101, 0b11100101, 0b00000101]))
+        );
+    }
+
     #[test]
     fn test_display() {
         let bv = Bitstring::from(BitVec::from_bytes(&[0b11100101, 0b11100101, 0b00000101]));
         let s = format!("{}", bv);
         assert_eq!(s, "111001011110010100000101");
     }
+
+    fn assert_round_trip(bytes: &[u8]) {
+        let decoded = Bitstring::from_raw(bytes).unwrap();
+        assert_eq!(decoded.to_raw(), bytes);
+        let re_decoded = Bitstring::from_raw(&decoded.to_raw()).unwrap();
+        assert_eq!(re_decoded.into_bitvec(), decoded.into_bitvec());
+    }
+
+    #[test]
+    fn round_trip_1() {
+        assert_round_trip(&[0, 0b01100101, 0b11100101, 0b00000101]);
+    }
+
+    #[test]
+    fn round_trip_2() {
+        assert_round_trip(&[1, 0b11100101, 0b11100101, 0b00000101]);
+    }
+
+    #[test]
+    fn round_trip_3() {
+        assert_round_trip(&[2, 0b11100101, 0b11100101, 0b00000101]);
+    }
+
+    #[test]
+    fn round_trip_4() {
+        assert_round_trip(&[3, 0b11100101, 0b11100101, 0b00000101]);
+    }
+
+    #[test]
+    fn round_trip_5() {
+        assert_round_trip(&[4, 0b11110101, 0b11100101, 0b00000101]);
+    }
+
+    #[test]
+    fn round_trip_6() {
+        assert_round_trip(&[5, 0b11111101, 0b11100101, 0b00000101]);
+    }
+
+    #[test]
+    fn round_trip_7() {
+        assert_round_trip(&[6, 0b11111101, 0b11100101, 0b00000101]);
+    }
+
+    #[test]
+    fn round_trip_8() {
+        assert_round_trip(&[7, 0b11111111, 0b11100101, 0b00000101]);
+    }
+
+    #[test]
+    fn test_popcount_and_bit_length() {
+        let bs = Bitstring::from(BitVec::from_bytes(&[0b10110000]));
+        assert_eq!(bs.popcount(), 3);
+        assert_eq!(bs.bit_length(), 8);
+    }
+
+    #[test]
+    fn test_get_bit_and_set_bit() {
+        let mut bs = Bitstring::from(BitVec::from_bytes(&[0b10110000]));
+        assert_eq!(bs.get_bit(0), Some(true));
+        assert_eq!(bs.get_bit(1), Some(false));
+
+        bs.set_bit(1, true);
+        assert_eq!(bs.get_bit(1), Some(true));
+        assert_eq!(format!("{bs}"), "11110000");
+    }
+
+    #[test]
+    fn test_bitand() {
+        let a = Bitstring::from(BitVec::from_bytes(&[0b10110011]));
+        let b = Bitstring::from(BitVec::from_bytes(&[0b11100101]));
+        let r = (&a & &b).unwrap();
+        assert_eq!(format!("{r}"), "10100001");
+    }
+
+    #[test]
+    fn test_bitor() {
+        let a = Bitstring::from(BitVec::from_bytes(&[0b10110011]));
+        let b = Bitstring::from(BitVec::from_bytes(&[0b11100101]));
+        let r = (&a | &b).unwrap();
+        assert_eq!(format!("{r}"), "11110111");
+    }
+
+    #[test]
+    fn test_bitxor() {
+        let a = Bitstring::from(BitVec::from_bytes(&[0b10110011]));
+        let b = Bitstring::from(BitVec::from_bytes(&[0b11100101]));
+        let r = (&a ^ &b).unwrap();
+        assert_eq!(format!("{r}"), "01010110");
+    }
+
+    #[test]
+    fn test_bitop_length_mismatch() {
+        let a = Bitstring::from(BitVec::from_bytes(&[0b10110011]));
+        let b = Bitstring::from(BitVec::from_elem(4, true));
+        let r = &a & &b;
+        assert!(matches!(
+            r,
+            Err(BitstringError::LengthMismatch { left: 8, right: 4 })
+        ));
+    }
+
+    #[test]
+    fn test_not() {
+        let a = Bitstring::from(BitVec::from_bytes(&[0b10110011]));
+        let r = !&a;
+        assert_eq!(format!("{r}"), "01001100");
+    }
+
+    #[test]
+    fn test_shl() {
+        let a = Bitstring::from(BitVec::from_bytes(&[0b10110011]));
+        let r = &a << 3;
+        assert_eq!(format!("{r}"), "10011000");
+    }
+
+    #[test]
+    fn test_shr() {
+        let a = Bitstring::from(BitVec::from_bytes(&[0b10110011]));
+        let r = &a >> 3;
+        assert_eq!(format!("{r}"), "00010110");
+    }
+
+    #[test]
+    fn test_shl_shift_larger_than_len_does_not_overflow() {
+        let a = Bitstring::from(BitVec::from_bytes(&[0b10110011]));
+        let r = &a << usize::MAX;
+        assert_eq!(format!("{r}"), "00000000");
+    }
+
+    #[test]
+    fn test_shr_shift_larger_than_len_does_not_overflow() {
+        let a = Bitstring::from(BitVec::from_bytes(&[0b10110011]));
+        let r = &a >> usize::MAX;
+        assert_eq!(format!("{r}"), "00000000");
+    }
 }
@@ -0,0 +1,87 @@
+use crate::Bitstring;
+use bit_vec::BitVec;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+impl Serialize for Bitstring<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&format!("{self}"))
+    }
+}
+
+struct BitstringVisitor;
+
+impl Visitor<'_> for BitstringVisitor {
+    type Value = Bitstring<'static>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a non-empty string of '0'/'1' characters")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v.is_empty() {
+            return Err(E::invalid_length(0, &self));
+        }
+
+        let mut bits = BitVec::with_capacity(v.len());
+        for c in v.chars() {
+            match c {
+                '0' => bits.push(false),
+                '1' => bits.push(true),
+                other => {
+                    return Err(E::invalid_value(
+                        de::Unexpected::Char(other),
+                        &"'0' or '1'",
+                    ))
+                }
+            }
+        }
+        Ok(Bitstring::from(bits))
+    }
+}
+
+impl<'de> Deserialize<'de> for Bitstring<'static> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(BitstringVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Bitstring;
+    use bit_vec::BitVec;
+
+    #[test]
+    fn test_serialize() {
+        let bs = Bitstring::from(BitVec::from_bytes(&[0b10110000]));
+        assert_eq!(serde_json::to_string(&bs).unwrap(), "\"10110000\"");
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let bs: Bitstring = serde_json::from_str("\"10110000\"").unwrap();
+        assert_eq!(format!("{bs}"), "10110000");
+    }
+
+    #[test]
+    fn test_deserialize_empty() {
+        let result: Result<Bitstring, _> = serde_json::from_str("\"\"");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_deserialize_invalid_char() {
+        let result: Result<Bitstring, _> = serde_json::from_str("\"102\"");
+        assert!(result.is_err());
+    }
+}
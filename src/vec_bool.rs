@@ -0,0 +1,32 @@
+use crate::Bitstring;
+use bit_vec::BitVec;
+
+impl From<Vec<bool>> for Bitstring<'static> {
+    fn from(bits: Vec<bool>) -> Self {
+        Bitstring::from(bits.into_iter().collect::<BitVec>())
+    }
+}
+
+impl From<Bitstring<'_>> for Vec<bool> {
+    fn from(value: Bitstring<'_>) -> Self {
+        value.iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Bitstring;
+
+    #[test]
+    fn test_from_vec_bool() {
+        let bs = Bitstring::from(vec![true, false, true, true]);
+        assert_eq!(format!("{bs}"), "1011");
+    }
+
+    #[test]
+    fn test_into_vec_bool() {
+        let bs = Bitstring::from(vec![true, false, true, true]);
+        let bits: Vec<bool> = bs.into();
+        assert_eq!(bits, vec![true, false, true, true]);
+    }
+}